@@ -0,0 +1,113 @@
+use super::hashmap::RHMap;
+use super::map_entry::{MapEntry, SlotEntry};
+use std::hash::{BuildHasher, Hash};
+
+/// A view into a single entry in an `RHMap`, which may either be vacant or occupied.
+///
+/// This enum is constructed from the `entry` method on `RHMap`.
+pub enum Entry<'a, K: Hash + Eq, V, H: BuildHasher + Clone> {
+    Occupied(OccupiedEntry<'a, K, V, H>),
+    Vacant(VacantEntry<'a, K, V, H>),
+}
+
+impl<'a, K: Hash + Eq, V, H: BuildHasher + Clone> Entry<'a, K, V, H> {
+    /// Ensures a value is in the entry by inserting the provided value if the entry is vacant,
+    /// then returns a mutable reference to the value in the entry.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the provided closure if the
+    /// entry is vacant, then returns a mutable reference to the value in the entry.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any potential inserts.
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+}
+
+/// A view into an occupied entry in an `RHMap`. It is part of the `Entry` enum.
+pub struct OccupiedEntry<'a, K: Hash + Eq, V, H: BuildHasher + Clone> {
+    pub(crate) map: &'a mut RHMap<K, V, H>,
+    pub(crate) index: usize,
+}
+
+impl<'a, K: Hash + Eq, V, H: BuildHasher + Clone> OccupiedEntry<'a, K, V, H> {
+    fn slot(&self) -> &SlotEntry<K, V> {
+        match self.map.inner.get(self.index).unwrap() {
+            MapEntry::Occupied(entry) => entry,
+            MapEntry::VacantEntry => unreachable!("OccupiedEntry must point at an occupied slot"),
+        }
+    }
+
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        &self.slot().key
+    }
+
+    /// Gets a reference to the value in the entry.
+    pub fn get(&self) -> &V {
+        &self.slot().value
+    }
+
+    /// Gets a mutable reference to the value in the entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.inner[self.index].as_occupied_mut().value
+    }
+
+    /// Converts the entry into a mutable reference to its value, bound to the lifetime of the map.
+    pub fn into_mut(self) -> &'a mut V {
+        let OccupiedEntry { map, index } = self;
+        &mut map.inner[index].as_occupied_mut().value
+    }
+
+    /// Sets the value of the entry, returning the previous value.
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+}
+
+/// A view into a vacant entry in an `RHMap`. It is part of the `Entry` enum.
+pub struct VacantEntry<'a, K: Hash + Eq, V, H: BuildHasher + Clone> {
+    pub(crate) map: &'a mut RHMap<K, V, H>,
+    pub(crate) key: K,
+    pub(crate) hash: usize,
+}
+
+impl<'a, K: Hash + Eq, V, H: BuildHasher + Clone> VacantEntry<'a, K, V, H> {
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Sets the value of the entry, returning a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let VacantEntry { map, key, hash } = self;
+        let index = map.insert_entry(SlotEntry::new(key, value, hash, 0));
+
+        &mut map.inner[index].as_occupied_mut().value
+    }
+}
@@ -0,0 +1,23 @@
+use std::fmt;
+
+/// The error returned by fallible allocation methods like `try_reserve`/`try_insert`, in place of
+/// the process abort that `Vec::with_capacity`/`Vec::reserve` would otherwise trigger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested capacity (or the number of items it must hold) overflows `usize`, or would
+    /// exceed `isize::MAX` bytes once rounded up to the next power of two.
+    CapacityOverflow,
+    /// The allocator returned an error for a request that wasn't itself too large.
+    AllocError,
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryReserveError::CapacityOverflow => write!(f, "capacity overflow"),
+            TryReserveError::AllocError => write!(f, "memory allocation failed"),
+        }
+    }
+}
+
+impl std::error::Error for TryReserveError {}
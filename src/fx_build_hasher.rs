@@ -1,12 +1,12 @@
 use rustc_hash::FxHasher;
 use std::hash::BuildHasher;
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct FxBuildHasher;
 
 impl FxBuildHasher {
     pub fn new() -> Self {
-        Self {}
+        Self
     }
 }
 
@@ -1,22 +1,36 @@
+use super::entry::{Entry, OccupiedEntry, VacantEntry};
+use super::error::TryReserveError;
 use super::fx_build_hasher::FxBuildHasher;
-use super::map_entry::{Entry, MapEntry};
+use super::iter::{Drain, IntoIter, Iter, IterMut, Keys, Values, ValuesMut};
+use super::map_entry::{MapEntry, SlotEntry};
+use super::resize_policy::ResizePolicy;
 use std::{
+    borrow::Borrow,
     cmp::max,
-    hash::{BuildHasher, Hash, Hasher},
-    ptr,
+    hash::{BuildHasher, Hash},
 };
 
 const INITIAL_SIZE: usize = 4;
 
-// TODO: Complete robinhood implementation.
+/// Rounds `capacity` up to the next power of two, leaving `0` as-is so a default-constructed map
+/// stays lazily unallocated. The backing table is always sized as a power of two so that probing
+/// can mask (`hash & (len - 1)`) instead of dividing (`hash % len`).
+fn backing_capacity(capacity: usize) -> usize {
+    if capacity == 0 {
+        0
+    } else {
+        capacity.next_power_of_two()
+    }
+}
 
 /// Robinhood HashMap backed by the fx hashing algorithm (by default).
 #[derive(Debug)]
 pub struct RHMap<K: Hash + Eq, V, H: BuildHasher + Clone> {
-    inner: Vec<MapEntry<K, V>>,
+    pub(crate) inner: Vec<MapEntry<K, V>>,
     hasher_builder: H,
-    num_items: usize,
-    max_psl: usize,
+    pub(crate) num_items: usize,
+    pub(crate) max_psl: usize,
+    resize_policy: ResizePolicy,
 }
 
 impl<K: Hash + Eq, V> RHMap<K, V, FxBuildHasher> {
@@ -29,25 +43,35 @@ impl<K: Hash + Eq, V> RHMap<K, V, FxBuildHasher> {
             hasher_builder,
             num_items: 0,
             max_psl: 0,
+            resize_policy: ResizePolicy::default(),
         }
     }
 
     /// Constructs a `RHMap` with an initial capacity. This method of constructing is recommended if you have a good idea of how large
-    /// your hashmap will grow as this reduces the number of resizes.
+    /// your hashmap will grow as this reduces the number of resizes. The actual backing capacity
+    /// is rounded up to the next power of two to support masked probing.
     pub fn with_capacity(initial_capacity: usize) -> Self {
         let hasher_builder = FxBuildHasher::new();
-        let mut inner: Vec<MapEntry<K, V>> = Vec::with_capacity(initial_capacity);
-        inner.extend((0..initial_capacity).map(|_| MapEntry::default()));
+        let capacity = backing_capacity(initial_capacity);
+        let mut inner: Vec<MapEntry<K, V>> = Vec::with_capacity(capacity);
+        inner.extend((0..capacity).map(|_| MapEntry::default()));
 
         Self {
             inner,
             hasher_builder,
             num_items: 0,
             max_psl: 0,
+            resize_policy: ResizePolicy::default(),
         }
     }
 }
 
+impl<K: Hash + Eq, V> Default for RHMap<K, V, FxBuildHasher> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<K: Hash + Eq, V, H: BuildHasher + Clone> RHMap<K, V, H> {
     /// Creates a `RHMap` with a custom hasher builder which overrides the default fx hasher. Use this if you want to create a
     /// robinhood hashmap but with a custom hasher perhaps to provide greater cryptographic security.
@@ -57,120 +81,182 @@ impl<K: Hash + Eq, V, H: BuildHasher + Clone> RHMap<K, V, H> {
             hasher_builder,
             num_items: 0,
             max_psl: 0,
+            resize_policy: ResizePolicy::default(),
         }
     }
 
-    /// Creates a `RHMap` with both an initial capacity and a custom hasher.
+    /// Creates a `RHMap` with both an initial capacity and a custom hasher. The actual backing
+    /// capacity is rounded up to the next power of two to support masked probing.
     pub fn with_capacity_and_hasher(initial_capacity: usize, hasher_builder: H) -> Self {
         let mut map = RHMap::with_hasher(hasher_builder);
-        let mut inner: Vec<MapEntry<K, V>> = Vec::with_capacity(initial_capacity);
-        inner.extend((0..initial_capacity).map(|_| MapEntry::default()));
+        let capacity = backing_capacity(initial_capacity);
+        let mut inner: Vec<MapEntry<K, V>> = Vec::with_capacity(capacity);
+        inner.extend((0..capacity).map(|_| MapEntry::default()));
         map.inner = inner;
 
         map
     }
 
+    /// Overrides the max load factor used to decide when `insert`/`entry` should trigger a
+    /// `resize`. Consuming builder method, so it chains onto any of the constructors above.
+    pub fn with_resize_policy(mut self, resize_policy: ResizePolicy) -> Self {
+        self.resize_policy = resize_policy;
+        self
+    }
+
     /// Inserts a value with its associated key into the hashmap. Time complexity should be amortized O(1).
     pub fn insert(&mut self, key: K, value: V) {
-        // Load Factor of 0.75
-        if self.inner.is_empty() || self.num_items > 3 * self.inner.len() / 4 {
+        if self.resize_policy.should_resize(self.num_items, self.inner.len()) {
             self.resize();
         }
 
         let hash = self.hash_key(&key);
         // Handles insertion logic
-        self.insert_entry(Entry::new(key, value, hash, 0));
+        self.insert_entry(SlotEntry::new(key, value, hash, 0));
     }
 
-    /// Deletes the entry with the given key. Returns an `Err` if no such entry with the provided key exists.
-    pub fn remove(&mut self, key: &K) -> Result<(), &'static str> {
-        // We're going to go with an interesting approach called backward shift deletion here
-        let res = self.get_entry(&key);
-        if let Some(entry) = res {
-            let len = self.inner.len();
-            let slot = entry.hash % len;
-            // Index position of the entry to be deleted
-            let i = slot + entry.psl;
-            // To keep track of where the bucket ends so that we can shift all entries to the right of the entry
-            // to be deleted to the left.
-            let mut j = i + 1;
-
-            // This is possible if the entry to be deleted is actually the last element
-            // of the inner vector. In this case, we actually don't have any elements (to the left) to shift
-            // so all we do is directly overwrite the value at index i to be a `MapEntry::VacantEntry`
-            if j >= self.inner.len() {
-                self.inner[i] = MapEntry::VacantEntry;
-                self.num_items -= 1;
-                return Ok(());
-            }
+    /// Reserves capacity for at least `additional` more entries, returning an error instead of
+    /// aborting the process if the required capacity would overflow or the allocator can't
+    /// satisfy the request. Unlike `insert`'s implicit resizing, the table is only rehashed once
+    /// the new backing allocation has actually succeeded.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self
+            .num_items
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        let target = self
+            .resize_policy
+            .min_capacity_for(required)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        if target <= self.inner.len() {
+            return Ok(());
+        }
 
-            loop {
-                let cur = self.inner.get(j).unwrap();
+        let bytes = target.checked_mul(std::mem::size_of::<MapEntry<K, V>>());
+        if bytes.map_or(true, |bytes| bytes > isize::MAX as usize) {
+            return Err(TryReserveError::CapacityOverflow);
+        }
 
-                // We overflow the bucket if we find an entry with psl == 0.
-                // We can also stop if we see a vacant entry because there can't be any valid
-                // occupied entries after a vacant entry (unless we overflow to the next bucket)
-                if let MapEntry::Occupied(entry) = cur {
-                    if entry.psl == 0 {
-                        break;
-                    }
-                } else {
-                    break;
-                }
+        let mut inner: Vec<MapEntry<K, V>> = Vec::new();
+        inner
+            .try_reserve_exact(target)
+            .map_err(|_| TryReserveError::AllocError)?;
+        inner.extend((0..target).map(|_| MapEntry::default()));
 
-                j += 1;
-            }
+        self.rehash_to(inner);
+        Ok(())
+    }
 
-            // unsafe because UB if we go out of bounds, any of the pointers are invalid or we mess up the vec pointer while modifying
-            // we should guarantee that indices i and j are within bounds
-            unsafe {
-                // Replace the entry to be deleted by shifting j - i - 1 elements to the left, overwriting
-                // the entry to be deleted in the process
-                let entry_ptr = self.inner.as_mut_ptr().add(i);
-                ptr::copy(entry_ptr.offset(1), entry_ptr, j - i - 1);
-                // We have to ensure that we add back in a `VacantEntry` after shifting all the elements of the bucket
-                // thereby taking the place of the deleted entry in order to not mess up the vec's structure.
-                ptr::write(entry_ptr.add(j - i - 1), MapEntry::VacantEntry);
-            }
+    /// Fallible counterpart to `insert`: reserves capacity for the new entry via `try_reserve`
+    /// before inserting, returning the previous value if the key was already present.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, TryReserveError> {
+        self.try_reserve(1)?;
 
-            self.num_items -= 1;
-            return Ok(());
-        } else {
-            return Err("Entry not found");
+        if let Some(existing) = self.get_mut(&key) {
+            return Ok(Some(std::mem::replace(existing, value)));
+        }
+
+        let hash = self.hash_key(&key);
+        self.insert_entry(SlotEntry::new(key, value, hash, 0));
+        Ok(None)
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place manipulation, so callers
+    /// don't have to pair a `get`/`contains_key` check with a separate `insert`. An occupied entry
+    /// is resolved in a single probe; inserting through a vacant entry probes the table again
+    /// from the key's home slot.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, H> {
+        if self.resize_policy.should_resize(self.num_items, self.inner.len()) {
+            self.resize();
+        }
+
+        match self.find_index(&key) {
+            Some(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            None => {
+                let hash = self.hash_key(&key);
+                Entry::Vacant(VacantEntry { map: self, key, hash })
+            }
         }
     }
 
-    fn insert_entry(&mut self, mut entry: Entry<K, V>) {
-        let slot = entry.hash % self.inner.len();
-        let mut i = slot;
+    /// Deletes the entry with the given key. Returns an `Err` if no such entry with the provided key exists.
+    pub fn remove<Q>(&mut self, key: &Q) -> Result<(), &'static str>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        // We're going to go with an interesting approach called backward shift deletion here.
+        // `find_index` already probes to the exact slot holding the entry, so there's no need to
+        // recompute it from the entry's home slot and psl.
+        let hole = match self.find_index(key) {
+            Some(index) => index,
+            None => return Err("Entry not found"),
+        };
+
+        let mask = self.inner.len() - 1;
+        let mut hole = hole;
 
         loop {
-            let cur = self.inner.get_mut(i);
-            // We've probably reached the end of the backing vector after probing and not finding an empty spot. We'll just append the new entry at this point.
-            // I'm not sure if this can ever happen but I'll just put it in here as a failsafe
-            if let None = cur {
-                self.inner.push(MapEntry::Occupied(entry));
+            let next = (hole + 1) & mask;
+
+            // We stop shifting once we hit a vacant slot, or an occupied entry that is already at
+            // its own home slot (psl == 0): there can't be any entries belonging to our bucket
+            // past either of those.
+            let should_shift =
+                matches!(&self.inner[next], MapEntry::Occupied(entry) if entry.psl > 0);
+
+            if !should_shift {
+                self.inner[hole] = MapEntry::VacantEntry;
                 break;
             }
 
-            let cur = cur.unwrap();
+            let mut moved = std::mem::replace(&mut self.inner[next], MapEntry::VacantEntry);
+            if let MapEntry::Occupied(entry) = &mut moved {
+                entry.psl -= 1;
+            }
+            self.inner[hole] = moved;
+            hole = next;
+        }
+
+        self.num_items -= 1;
+        Ok(())
+    }
+
+    /// Runs the Robin-Hood insertion swap loop and returns the index of the slot that now holds
+    /// `entry`'s key. Note that once a swap has occurred, the entry being carried through the
+    /// rest of the loop is the one that got displaced, not the original one, so only the *first*
+    /// placement (a direct vacancy, a value update, or the first swap) can be the final resting
+    /// place of the entry that was passed in.
+    pub(crate) fn insert_entry(&mut self, mut entry: SlotEntry<K, V>) -> usize {
+        let mask = self.inner.len() - 1;
+        let mut i = entry.hash & mask;
+        let mut placed_at = None;
+
+        loop {
+            // The table is always a power-of-two number of slots and `i` is kept within
+            // `0..self.inner.len()` by masking, so this index is always in bounds.
+            let cur = self.inner.get_mut(i).unwrap();
+
             if let MapEntry::Occupied(occupied_entry) = cur {
                 if occupied_entry.key == entry.key {
                     // Update value
                     let _ = std::mem::replace(occupied_entry, entry);
                     // Return to prevent updating num items.
-                    return;
+                    return i;
                 }
 
                 if entry.psl > occupied_entry.psl {
                     std::mem::swap(&mut entry, occupied_entry);
+                    placed_at.get_or_insert(i);
                     continue;
                 }
 
-                i += 1;
+                i = (i + 1) & mask;
             } else {
                 // Insert entry into the vacancy.
                 let _ = std::mem::replace(cur, MapEntry::Occupied(entry));
+                placed_at.get_or_insert(i);
                 break;
             }
 
@@ -179,6 +265,54 @@ impl<K: Hash + Eq, V, H: BuildHasher + Clone> RHMap<K, V, H> {
         }
 
         self.num_items += 1;
+        placed_at.unwrap()
+    }
+
+    /// Runs the Robin-Hood insertion swap loop for an entry whose key is already known not to
+    /// collide with any key currently in the table, skipping the equality check (and therefore
+    /// the value-update branch) that `insert_entry` needs for the general case. Used internally
+    /// by `rehash_to`, where every entry being moved between tables was already a distinct key in
+    /// the old one.
+    fn insert_entry_unique(&mut self, mut entry: SlotEntry<K, V>) {
+        let mask = self.inner.len() - 1;
+        let mut i = entry.hash & mask;
+
+        loop {
+            let cur = self.inner.get_mut(i).unwrap();
+
+            if let MapEntry::Occupied(occupied_entry) = cur {
+                if entry.psl > occupied_entry.psl {
+                    std::mem::swap(&mut entry, occupied_entry);
+                }
+            } else {
+                let _ = std::mem::replace(cur, MapEntry::Occupied(entry));
+                self.num_items += 1;
+                return;
+            }
+
+            entry.psl += 1;
+            self.max_psl = max(self.max_psl, entry.psl);
+            i = (i + 1) & mask;
+        }
+    }
+
+    /// Inserts `key`/`value` via the same swap loop as `insert_entry_unique`, skipping the check
+    /// for an existing entry with the same key. Intended for bulk loads already known to contain
+    /// no duplicate keys, where paying for that check on every element would be wasted work.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee `key` is not already present in the map. Inserting a duplicate
+    /// key through this path leaves the map in an unspecified state: lookups for that key (or any
+    /// key whose probe sequence crosses the duplicate) may return the wrong value or `None`, and
+    /// `len` will overcount the map's actual distinct entries.
+    pub unsafe fn insert_unique_unchecked(&mut self, key: K, value: V) {
+        if self.resize_policy.should_resize(self.num_items, self.inner.len()) {
+            self.resize();
+        }
+
+        let hash = self.hash_key(&key);
+        self.insert_entry_unique(SlotEntry::new(key, value, hash, 0));
     }
 
     /// Gets the appropriate value given a valid key. Returns `None` if the key value mapping does not exist.
@@ -191,47 +325,77 @@ impl<K: Hash + Eq, V, H: BuildHasher + Clone> RHMap<K, V, H> {
     /// over the maximum search time in case of the standard FCFS collision strategy.
     ///
     /// tl;dr - In general, even in the worst case, we can effectively consider lookup to be O(1) time.
-    pub fn get(&self, key: &K) -> Option<&V> {
-        if let Some(entry) = self.get_entry(key) {
-            return Some(&entry.value);
-        } else {
-            return None;
-        }
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_entry(key).map(|entry| &entry.value)
+    }
+
+    /// Gets a mutable reference to the value associated with the given key. Returns `None` if
+    /// the key value mapping does not exist.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_index(key)?;
+        Some(&mut self.inner[index].as_occupied_mut().value)
     }
 
     /// There are some additional (minor) optimizations in place here. Namely:
     /// We return nothing if we encounter an entry with a psl less than the number of steps we've walked.
     /// We return nothing if the number of steps we've walked exceeds the maximum psl value ever recorded.
-    fn get_entry(&self, key: &K) -> Option<&Entry<K, V>> {
-        let hash = self.hash_key(key);
-        let slot = hash % self.inner.len();
-        let mut d = slot;
-
-        while d < self.inner.len() {
-            let cur = self.inner.get(d).unwrap();
-            if let MapEntry::Occupied(entry) = cur {
-                if entry.key == *key {
-                    return Some(entry);
-                }
+    fn get_entry<Q>(&self, key: &Q) -> Option<&SlotEntry<K, V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_index(key)?;
+        match self.inner.get(index).unwrap() {
+            MapEntry::Occupied(entry) => Some(entry),
+            MapEntry::VacantEntry => None,
+        }
+    }
 
-                // If we walked d steps and we encounter an entry that is some distance less than d from its home, we can stop.
-                // OR: Our probing has reached to a point where it is impossible to find an entry this far out from home so we
-                // can confidently stop in this case as well.
-                if entry.psl < d || d > self.max_psl {
-                    return None;
-                }
+    /// Locates the backing index of the slot holding `key`, if present. Shared by `get_entry`
+    /// and the `entry` API, so a lookup or an occupied entry costs one walk of the probe sequence.
+    pub(crate) fn find_index<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.inner.is_empty() {
+            return None;
+        }
 
-                if d > self.max_psl {
-                    return None;
+        let mask = self.inner.len() - 1;
+        let hash = self.hash_key(key);
+        let home = hash & mask;
+        let mut step = 0;
+
+        // Bounded by `max_psl`: an entry stored `step` slots past its home slot has a psl of
+        // `step`, and no entry in the table has a psl above `max_psl`.
+        while step <= self.max_psl {
+            let index = (home + step) & mask;
+            match self.inner.get(index).unwrap() {
+                MapEntry::Occupied(entry) => {
+                    if entry.key.borrow() == key {
+                        return Some(index);
+                    }
+
+                    if entry.psl < step {
+                        return None;
+                    }
                 }
-            } else {
-                return None;
+                MapEntry::VacantEntry => return None,
             }
 
-            d += 1;
+            step += 1;
         }
 
-        return None;
+        None
     }
 
     /// Clears all entries but preserves the allocated memory for use later.
@@ -246,16 +410,16 @@ impl<K: Hash + Eq, V, H: BuildHasher + Clone> RHMap<K, V, H> {
         }
 
         self.num_items = 0;
+        self.max_psl = 0;
     }
 
     /// Checks to see if a value is associated with the given key.
-    pub fn contains_key(&self, key: &K) -> bool {
-        let entry = self.get_entry(key);
-        if let Some(_) = entry {
-            return true;
-        } else {
-            return false;
-        }
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_entry(key).is_some()
     }
 
     /// Gets the length / number of entries of the hashmap.
@@ -263,42 +427,169 @@ impl<K: Hash + Eq, V, H: BuildHasher + Clone> RHMap<K, V, H> {
         self.num_items
     }
 
+    /// Returns `true` if the hashmap contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.num_items == 0
+    }
+
     /// Gets the capacity of the hashmap.
     pub fn capacity(&self) -> usize {
         self.inner.len()
     }
 
-    /// Allocates a new map of a different size and then moves the contents of the previous map into it.
-    fn resize(&mut self) {
-        let target_size: usize = match self.inner.len() {
-            0 => INITIAL_SIZE,
-            n => 2 * n,
-        };
+    /// Returns an iterator over the key-value pairs of the map, in arbitrary order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.inner.iter(),
+        }
+    }
+
+    /// Returns a mutable iterator over the key-value pairs of the map, in arbitrary order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self.inner.iter_mut(),
+        }
+    }
+
+    /// Returns an iterator over the keys of the map, in arbitrary order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { inner: self.iter() }
+    }
+
+    /// Returns an iterator over the values of the map, in arbitrary order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { inner: self.iter() }
+    }
+
+    /// Returns a mutable iterator over the values of the map, in arbitrary order.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            inner: self.iter_mut(),
+        }
+    }
+
+    /// Clears the map and returns an iterator over the removed key-value pairs. The backing
+    /// capacity is preserved, just like `clear`.
+    pub fn drain(&mut self) -> Drain<K, V> {
+        let capacity = self.inner.len();
+        let old_inner = std::mem::replace(&mut self.inner, Vec::with_capacity(capacity));
+        self.inner.extend((0..capacity).map(|_| MapEntry::default()));
+        self.num_items = 0;
+        self.max_psl = 0;
+
+        Drain {
+            inner: old_inner.into_iter(),
+        }
+    }
+
+    /// Replaces the backing table with `inner` (already sized and filled with vacant slots),
+    /// re-probing every occupied entry into it. Shared by `resize` (which doubles, or starts at
+    /// `INITIAL_SIZE` from an empty table) and `try_reserve` (which grows to the power of two
+    /// that `ResizePolicy::min_capacity_for` picks, and only calls this once the allocation has
+    /// already succeeded).
+    fn rehash_to(&mut self, inner: Vec<MapEntry<K, V>>) {
+        let mut new_map = Self::with_hasher(self.hasher_builder.clone())
+            .with_resize_policy(self.resize_policy);
+        new_map.inner = inner;
 
-        let mut new_map = Self::with_capacity_and_hasher(target_size, self.hasher_builder.clone());
         // Filters out all vacant entries since we don't care about those.
         let entries = self.inner.drain(0..).filter_map(|entry| {
             if let MapEntry::Occupied(inner_entry) = entry {
-                return Some(inner_entry);
+                Some(inner_entry)
             } else {
-                return None;
+                None
             }
         });
 
-        for entry in entries {
-            // Transfer ownership
-            new_map.insert_entry(entry);
+        for mut entry in entries {
+            // The entry's psl was relative to the old table's mask, so it has to be reset before
+            // re-probing it against the new one. Every entry here was already a distinct key in
+            // the old table, so the unique fast path skips a redundant equality check per entry.
+            entry.psl = 0;
+            new_map.insert_entry_unique(entry);
         }
 
         // Replace with the new resized hashmap.
         let _ = std::mem::replace(self, new_map);
     }
 
-    /// Builds a new hasher, hashes the provided key and returns the hash.
-    fn hash_key(&self, key: &K) -> usize {
-        let mut hasher = self.hasher_builder.build_hasher();
-        key.hash(&mut hasher);
-        hasher.finish() as usize
+    /// Allocates a new map of a different size and then moves the contents of the previous map into it.
+    fn resize(&mut self) {
+        let target_size: usize = match self.inner.len() {
+            0 => INITIAL_SIZE,
+            n => 2 * n,
+        };
+
+        let mut inner = Vec::with_capacity(target_size);
+        inner.extend((0..target_size).map(|_| MapEntry::default()));
+        self.rehash_to(inner);
+    }
+
+    /// Builds a new hasher, hashes the provided key and returns the hash. Generic over `Q` (with
+    /// `K: Borrow<Q>`) so that, e.g., a `RHMap<String, V>` hashes a `&str` lookup key identically
+    /// to the `String` it was inserted under.
+    fn hash_key<Q>(&self, key: &Q) -> usize
+    where
+        Q: Hash + ?Sized,
+    {
+        self.hasher_builder.hash_one(key) as usize
+    }
+}
+
+impl<K: Hash + Eq, V, H: BuildHasher + Clone> IntoIterator for RHMap<K, V, H> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.inner.into_iter(),
+        }
+    }
+}
+
+impl<'a, K: Hash + Eq, V, H: BuildHasher + Clone> IntoIterator for &'a RHMap<K, V, H> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: Hash + Eq, V, H: BuildHasher + Clone> IntoIterator for &'a mut RHMap<K, V, H> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: Hash + Eq, V> std::iter::FromIterator<(K, V)> for RHMap<K, V, FxBuildHasher> {
+    /// Builds a map from an iterator, sizing the initial capacity from the iterator's lower size
+    /// hint so that inserting that many entries doesn't immediately trip a resize under the
+    /// default `ResizePolicy` (~0.909 load factor).
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        let capacity = ResizePolicy::default()
+            .min_capacity_for(lower)
+            .unwrap_or(lower);
+        let mut map = RHMap::with_capacity(capacity);
+
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+
+        map
+    }
+}
+
+impl<K: Hash + Eq, V, H: BuildHasher + Clone> Extend<(K, V)> for RHMap<K, V, H> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
     }
 }
 
@@ -309,10 +600,9 @@ mod tests {
 
     #[test]
     fn it_constructs_with_an_initial_capacity() {
-        let initial_capacity = 5;
-        let hashmap: RHMap<&str, i32, FxBuildHasher> = RHMap::with_capacity(initial_capacity);
+        let hashmap: RHMap<&str, i32, FxBuildHasher> = RHMap::with_capacity(5);
 
-        assert_eq!(hashmap.capacity(), initial_capacity);
+        assert_eq!(hashmap.capacity(), 8);
     }
 
     #[test]
@@ -338,7 +628,7 @@ mod tests {
         let value = "Eye lyked it alot.".to_string();
         book_reviews.insert(key, value);
 
-        assert_eq!(book_reviews.capacity(), 10);
+        assert_eq!(book_reviews.capacity(), 16);
         assert_eq!(
             *book_reviews
                 .get(&String::from("The Adventures of Sherlock Holmes"))
@@ -363,7 +653,7 @@ mod tests {
         hashmap.insert(42, 1);
         hashmap.clear();
 
-        assert_eq!(hashmap.capacity(), 70);
+        assert_eq!(hashmap.capacity(), 128);
         assert_eq!(hashmap.len(), 0);
         assert_eq!(hashmap.contains_key(&42), false);
     }
@@ -387,18 +677,187 @@ mod tests {
         assert!(!hashmap.contains_key(&1));
         assert!(!hashmap.contains_key(&7));
         assert!(!hashmap.contains_key(&3));
-        assert_eq!(hashmap.len(), 4)
+        assert_eq!(hashmap.len(), 1)
     }
 
     #[test]
     #[allow(unused_must_use)]
     fn it_removes_edge_case_entry() {
-        // An edge case entry
+        // An edge case entry: a single item in a capacity-1 table is already over the default
+        // ~0.909 load factor, so the first insert resizes before it lands.
         let mut hashmap = RHMap::with_capacity(1);
         hashmap.insert(1, 2);
         hashmap.remove(&1);
         assert!(!hashmap.contains_key(&1));
         assert_eq!(hashmap.len(), 0);
-        assert_eq!(hashmap.capacity(), 1);
+        assert_eq!(hashmap.capacity(), 2);
+    }
+
+    #[test]
+    fn entry_or_insert_inserts_when_vacant() {
+        let mut hashmap = RHMap::new();
+
+        *hashmap.entry(1).or_insert(2) += 1;
+
+        assert_eq!(*hashmap.get(&1).unwrap(), 3);
+        assert_eq!(hashmap.len(), 1);
+    }
+
+    #[test]
+    fn entry_or_insert_mutates_when_occupied() {
+        let mut hashmap = RHMap::new();
+        hashmap.insert(1, 2);
+
+        *hashmap.entry(1).or_insert(0) += 1;
+
+        assert_eq!(*hashmap.get(&1).unwrap(), 3);
+        assert_eq!(hashmap.len(), 1);
+    }
+
+    #[test]
+    fn entry_and_modify_only_runs_on_occupied() {
+        let mut hashmap = RHMap::new();
+        hashmap.insert(1, 2);
+
+        hashmap.entry(1).and_modify(|v| *v += 10).or_insert(0);
+        hashmap.entry(2).and_modify(|v| *v += 10).or_insert(5);
+
+        assert_eq!(*hashmap.get(&1).unwrap(), 12);
+        assert_eq!(*hashmap.get(&2).unwrap(), 5);
+    }
+
+    #[test]
+    fn it_iterates_over_entries() {
+        let mut hashmap = RHMap::new();
+        for x in 0..20 {
+            hashmap.insert(x, x * 2);
+        }
+
+        let mut seen: Vec<(i32, i32)> = hashmap.iter().map(|(k, v)| (*k, *v)).collect();
+        seen.sort();
+
+        assert_eq!(seen, (0..20).map(|x| (x, x * 2)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_iterates_mutably_over_values() {
+        let mut hashmap = RHMap::new();
+        for x in 0..10 {
+            hashmap.insert(x, x);
+        }
+
+        for value in hashmap.values_mut() {
+            *value *= 10;
+        }
+
+        let mut values: Vec<i32> = hashmap.values().copied().collect();
+        values.sort();
+
+        assert_eq!(values, (0..10).map(|x| x * 10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_drains_all_entries() {
+        let mut hashmap = RHMap::with_capacity(70);
+        for x in 0..10 {
+            hashmap.insert(x, x + 1);
+        }
+
+        let mut drained: Vec<(i32, i32)> = hashmap.drain().collect();
+        drained.sort();
+
+        assert_eq!(drained, (0..10).map(|x| (x, x + 1)).collect::<Vec<_>>());
+        assert_eq!(hashmap.len(), 0);
+        assert_eq!(hashmap.capacity(), 128);
+        assert!(hashmap.iter().next().is_none());
+    }
+
+    #[test]
+    fn it_builds_from_iterator_and_extends() {
+        let mut hashmap: RHMap<i32, i32, FxBuildHasher> =
+            (0..10).map(|x| (x, x * 3)).collect();
+        hashmap.extend((10..20).map(|x| (x, x * 3)));
+
+        assert_eq!(hashmap.len(), 20);
+        for x in 0..20 {
+            assert_eq!(*hashmap.get(&x).unwrap(), x * 3);
+        }
+    }
+
+    #[test]
+    fn it_looks_up_string_keys_by_borrowed_str() {
+        let mut hashmap = RHMap::new();
+        hashmap.insert("Sherlock Holmes".to_string(), 1887);
+
+        assert_eq!(*hashmap.get("Sherlock Holmes").unwrap(), 1887);
+        assert!(hashmap.contains_key("Sherlock Holmes"));
+        assert!(hashmap.remove("Sherlock Holmes").is_ok());
+        assert!(!hashmap.contains_key("Sherlock Holmes"));
+    }
+
+    #[test]
+    fn it_reserves_capacity_without_losing_entries() {
+        let mut hashmap = RHMap::new();
+        hashmap.insert(1, "a");
+        hashmap.insert(2, "b");
+
+        assert_eq!(hashmap.try_reserve(100), Ok(()));
+        assert!(hashmap.capacity() >= 102);
+        assert_eq!(hashmap.get(&1), Some(&"a"));
+        assert_eq!(hashmap.get(&2), Some(&"b"));
+    }
+
+    #[test]
+    fn it_rejects_reserving_an_overflowing_capacity() {
+        let mut hashmap: RHMap<i32, i32, FxBuildHasher> = RHMap::new();
+
+        assert_eq!(
+            hashmap.try_reserve(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+    }
+
+    #[test]
+    fn it_try_inserts_new_and_existing_keys() {
+        let mut hashmap = RHMap::new();
+
+        assert_eq!(hashmap.try_insert(1, "a"), Ok(None));
+        assert_eq!(hashmap.try_insert(1, "b"), Ok(Some("a")));
+        assert_eq!(hashmap.get(&1), Some(&"b"));
+        assert_eq!(hashmap.len(), 1);
+    }
+
+    #[test]
+    fn it_bulk_inserts_known_distinct_keys() {
+        let mut hashmap = RHMap::new();
+
+        for x in 0..100 {
+            // Safe here because `x` ranges over distinct values.
+            unsafe {
+                hashmap.insert_unique_unchecked(x, x + 1);
+            }
+        }
+
+        assert_eq!(hashmap.len(), 100);
+        for x in 0..100 {
+            assert_eq!(*hashmap.get(&x).unwrap(), x + 1);
+        }
+    }
+
+    #[test]
+    fn it_survives_a_resize_after_bulk_inserting() {
+        let mut hashmap = RHMap::with_capacity(4);
+
+        for x in 0..20 {
+            unsafe {
+                hashmap.insert_unique_unchecked(x, x * 2);
+            }
+        }
+
+        assert_eq!(hashmap.len(), 20);
+        assert!(hashmap.capacity() >= 20);
+        for x in 0..20 {
+            assert_eq!(*hashmap.get(&x).unwrap(), x * 2);
+        }
     }
 }
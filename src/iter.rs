@@ -0,0 +1,132 @@
+use super::map_entry::MapEntry;
+use std::hash::Hash;
+
+/// An iterator over the entries of an `RHMap`, in arbitrary (slot) order.
+///
+/// This struct is created by the `iter` method on `RHMap`.
+pub struct Iter<'a, K: Hash + Eq, V> {
+    pub(crate) inner: std::slice::Iter<'a, MapEntry<K, V>>,
+}
+
+impl<'a, K: Hash + Eq, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for entry in self.inner.by_ref() {
+            if let MapEntry::Occupied(entry) = entry {
+                return Some((&entry.key, &entry.value));
+            }
+        }
+
+        None
+    }
+}
+
+/// A mutable iterator over the entries of an `RHMap`, in arbitrary (slot) order.
+///
+/// This struct is created by the `iter_mut` method on `RHMap`.
+pub struct IterMut<'a, K: Hash + Eq, V> {
+    pub(crate) inner: std::slice::IterMut<'a, MapEntry<K, V>>,
+}
+
+impl<'a, K: Hash + Eq, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for entry in self.inner.by_ref() {
+            if let MapEntry::Occupied(entry) = entry {
+                return Some((&entry.key, &mut entry.value));
+            }
+        }
+
+        None
+    }
+}
+
+/// An owning iterator over the entries of an `RHMap`, in arbitrary (slot) order.
+///
+/// This struct is created by the `into_iter` method on `RHMap` (provided by the `IntoIterator` trait).
+pub struct IntoIter<K: Hash + Eq, V> {
+    pub(crate) inner: std::vec::IntoIter<MapEntry<K, V>>,
+}
+
+impl<K: Hash + Eq, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for entry in self.inner.by_ref() {
+            if let MapEntry::Occupied(entry) = entry {
+                return Some((entry.key, entry.value));
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over the keys of an `RHMap`, in arbitrary (slot) order.
+///
+/// This struct is created by the `keys` method on `RHMap`.
+pub struct Keys<'a, K: Hash + Eq, V> {
+    pub(crate) inner: Iter<'a, K, V>,
+}
+
+impl<'a, K: Hash + Eq, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+}
+
+/// An iterator over the values of an `RHMap`, in arbitrary (slot) order.
+///
+/// This struct is created by the `values` method on `RHMap`.
+pub struct Values<'a, K: Hash + Eq, V> {
+    pub(crate) inner: Iter<'a, K, V>,
+}
+
+impl<'a, K: Hash + Eq, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+/// A mutable iterator over the values of an `RHMap`, in arbitrary (slot) order.
+///
+/// This struct is created by the `values_mut` method on `RHMap`.
+pub struct ValuesMut<'a, K: Hash + Eq, V> {
+    pub(crate) inner: IterMut<'a, K, V>,
+}
+
+impl<'a, K: Hash + Eq, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+/// A draining iterator over the entries of an `RHMap`, in arbitrary (slot) order.
+///
+/// This struct is created by the `drain` method on `RHMap`. Unlike the other iterators here, the
+/// map has already been emptied by the time this is returned, so it doesn't need to borrow it.
+pub struct Drain<K: Hash + Eq, V> {
+    pub(crate) inner: std::vec::IntoIter<MapEntry<K, V>>,
+}
+
+impl<K: Hash + Eq, V> Iterator for Drain<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for entry in self.inner.by_ref() {
+            if let MapEntry::Occupied(entry) = entry {
+                return Some((entry.key, entry.value));
+            }
+        }
+
+        None
+    }
+}
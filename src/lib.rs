@@ -0,0 +1,19 @@
+mod entry;
+mod error;
+mod fx_build_hasher;
+mod hashmap;
+mod iter;
+mod map_entry;
+mod resize_policy;
+// Requires the consuming Cargo.toml to declare an optional `serde` dependency and a matching
+// `serde = ["dep:serde"]` feature; this crate currently ships without a manifest at all, so that
+// declaration has to land wherever one is added.
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+pub use crate::entry::{Entry, OccupiedEntry, VacantEntry};
+pub use crate::error::TryReserveError;
+pub use crate::fx_build_hasher::FxBuildHasher;
+pub use crate::hashmap::RHMap;
+pub use crate::iter::{Drain, IntoIter, Iter, IterMut, Keys, Values, ValuesMut};
+pub use crate::resize_policy::ResizePolicy;
@@ -1,34 +1,31 @@
-use std::default::Default;
 use std::hash::Hash;
 
 type HashValue = usize;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
 pub enum MapEntry<K: Hash + Eq, V> {
-    Occupied(Entry<K, V>),
+    Occupied(SlotEntry<K, V>),
+    #[default]
     VacantEntry,
 }
 
 impl<K: Hash + Eq, V> MapEntry<K, V> {
-    /// Returns the contained `Occupied` map entry, consuming the self value.
-    /// This function will panic if you try to unwrap a `VacantEntry`.
-    pub fn unwrap(self) -> Entry<K, V> {
+    /// Returns a mutable reference to the contained `Occupied` map entry.
+    /// This function will panic if called on a `VacantEntry`.
+    pub fn as_occupied_mut(&mut self) -> &mut SlotEntry<K, V> {
         if let MapEntry::Occupied(entry) = self {
-            return entry;
+            entry
         } else {
             panic!("Expected an Occupied entry (non-vacant MapEntry) instead found a VacantEntry");
         }
     }
 }
 
-impl<K: Hash + Eq, V> Default for MapEntry<K, V> {
-    fn default() -> Self {
-        MapEntry::VacantEntry
-    }
-}
-
+/// The entry physically stored in a slot of the backing `Vec`. Not to be confused with the
+/// public `Entry` view types in the `entry` module, which borrow from the map instead of
+/// owning a slot.
 #[derive(Clone, Copy, Debug)]
-pub struct Entry<K: Hash + Eq, V> {
+pub struct SlotEntry<K: Hash + Eq, V> {
     pub key: K,
     pub value: V,
     pub hash: HashValue,
@@ -36,7 +33,7 @@ pub struct Entry<K: Hash + Eq, V> {
     pub psl: usize,
 }
 
-impl<K: Hash + Eq, V> Entry<K, V> {
+impl<K: Hash + Eq, V> SlotEntry<K, V> {
     pub fn new(key: K, value: V, hash: usize, psl: usize) -> Self {
         Self {
             key,
@@ -0,0 +1,58 @@
+/// Controls how full an `RHMap`'s backing table is allowed to get before `insert`/`entry`
+/// triggers a `resize`, expressed as a `max_load_numerator / max_load_denominator` fraction so
+/// the threshold check stays in integer arithmetic (no floating point on the hot path).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResizePolicy {
+    max_load_numerator: usize,
+    max_load_denominator: usize,
+}
+
+impl ResizePolicy {
+    /// Creates a policy that resizes once `num_items / capacity` would exceed
+    /// `max_load_numerator / max_load_denominator`. Panics if the fraction isn't strictly
+    /// between 0 and 1.
+    pub fn new(max_load_numerator: usize, max_load_denominator: usize) -> Self {
+        assert!(
+            max_load_numerator > 0 && max_load_numerator < max_load_denominator,
+            "max load factor must be a fraction in (0, 1)"
+        );
+
+        Self {
+            max_load_numerator,
+            max_load_denominator,
+        }
+    }
+
+    /// Returns whether inserting one more entry on top of the `num_items` already held in
+    /// `capacity` slots would push this policy past its load factor (or the table has no slots
+    /// at all yet). Checking against `num_items + 1` rather than `num_items` keeps the table from
+    /// ever actually reaching 100% load before a resize is triggered.
+    pub(crate) fn should_resize(&self, num_items: usize, capacity: usize) -> bool {
+        capacity == 0
+            || (num_items + 1) * self.max_load_denominator > self.max_load_numerator * capacity
+    }
+
+    /// Returns the smallest power-of-two capacity that can hold `num_items` without tripping
+    /// `should_resize`, or `None` if computing it would overflow `usize`. For `0` items it
+    /// returns `0`, leaving the table unallocated.
+    pub(crate) fn min_capacity_for(&self, num_items: usize) -> Option<usize> {
+        if num_items == 0 {
+            return Some(0);
+        }
+
+        let required = num_items.checked_add(1)?;
+        let scaled = required.checked_mul(self.max_load_denominator)?;
+        let needed = scaled
+            .checked_add(self.max_load_numerator - 1)?
+            .checked_div(self.max_load_numerator)?;
+
+        needed.checked_next_power_of_two()
+    }
+}
+
+impl Default for ResizePolicy {
+    /// The ~0.909 (10/11) max load factor used by the std `HashMap`.
+    fn default() -> Self {
+        Self::new(10, 11)
+    }
+}
@@ -0,0 +1,74 @@
+use super::fx_build_hasher::FxBuildHasher;
+use super::hashmap::RHMap;
+use super::map_entry::MapEntry;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+
+impl<K, V, H> Serialize for RHMap<K, V, H>
+where
+    K: Hash + Eq + Serialize,
+    V: Serialize,
+    H: BuildHasher + Clone,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.num_items))?;
+        for entry in &self.inner {
+            if let MapEntry::Occupied(entry) = entry {
+                map.serialize_entry(&entry.key, &entry.value)?;
+            }
+        }
+        map.end()
+    }
+}
+
+struct RHMapVisitor<K, V> {
+    marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'de, K, V> Visitor<'de> for RHMapVisitor<K, V>
+where
+    K: Hash + Eq + Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = RHMap<K, V, FxBuildHasher>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        // Rebuild via `insert` (rather than copying the serialized slots directly) so each
+        // entry's probe sequence length is recomputed for this process's table, instead of
+        // trusting whatever psl happened to be live when the source map was serialized.
+        let mut map = RHMap::with_capacity(access.size_hint().unwrap_or(0));
+        while let Some((key, value)) = access.next_entry()? {
+            map.insert(key, value);
+        }
+
+        Ok(map)
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for RHMap<K, V, FxBuildHasher>
+where
+    K: Hash + Eq + Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(RHMapVisitor {
+            marker: PhantomData,
+        })
+    }
+}